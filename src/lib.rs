@@ -16,6 +16,8 @@
 //! let avg = total as f64 / scores.len() as f64;
 //! ```
 //!
+//! Here `avg` divides `total` by `scores.len()`, with both sides cast to `f64`.
+//!
 //! ## How This Works
 //!
 //! The type annotations you see on hover are produced by a
@@ -64,7 +66,10 @@
 //!   cargo doc --no-deps
 //! ```
 
-use std::collections::HashMap;
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::str::FromStr;
 
 /// A strongly-typed configuration builder.
 ///
@@ -80,8 +85,10 @@ use std::collections::HashMap;
 /// ```
 pub struct Config {
     name: String,
+    host: String,
     port: u16,
     debug: bool,
+    listeners: HashMap<String, u16>,
 }
 
 impl Config {
@@ -89,28 +96,121 @@ impl Config {
     pub fn new(name: &str) -> Self {
         Self {
             name: name.to_string(),
+            host: "0.0.0.0".to_string(),
             port: 3000,
             debug: false,
+            listeners: HashMap::new(),
         }
     }
 
+    /// Create a configuration for a named environment profile.
+    ///
+    /// Reads `APP_{PROFILE}_PORT` and `APP_{PROFILE}_DEBUG`, with the profile
+    /// name uppercased and any non-alphanumeric character replaced by `_`, so
+    /// `pre-prod` reads `APP_PRE_PROD_PORT`. Missing or unparsable values keep
+    /// their defaults. `_DEBUG` only accepts `true` or `false`, so `1` leaves
+    /// debug off.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // SAFETY: no other thread reads these variables.
+    /// unsafe {
+    ///     std::env::set_var("APP_PROD_PORT", "443");
+    ///     std::env::set_var("APP_STAGING_PORT", "8443");
+    ///     std::env::set_var("APP_STAGING_DEBUG", "true");
+    ///     std::env::set_var("APP_PRE_PROD_PORT", "8444");
+    /// }
+    ///
+    /// let prod = twoslash_demo::Config::from_profile("my-app", "prod");
+    /// let staging = twoslash_demo::Config::from_profile("my-app", "staging");
+    /// let pre_prod = twoslash_demo::Config::from_profile("my-app", "pre-prod");
+    /// let unknown = twoslash_demo::Config::from_profile("my-app", "nowhere");
+    ///
+    /// assert_eq!(prod.address(), "0.0.0.0:443");
+    /// assert_eq!(staging.address(), "0.0.0.0:8443");
+    /// assert_eq!(pre_prod.address(), "0.0.0.0:8444");
+    /// assert!(staging.debug());
+    /// assert!(!prod.debug());
+    /// assert_eq!(unknown.address(), "0.0.0.0:3000");
+    /// ```
+    pub fn from_profile(name: &str, profile: &str) -> Self {
+        let profile: String = profile
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_uppercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        let prefix = format!("APP_{profile}");
+        let mut config = Self::new(name);
+        if let Some(port) = env_var(&format!("{prefix}_PORT")) {
+            config.port = port;
+        }
+        if let Some(debug) = env_var(&format!("{prefix}_DEBUG")) {
+            config.debug = debug;
+        }
+        config
+    }
+
+    /// Set the host to bind to.
+    pub fn with_host(mut self, host: &str) -> Self {
+        self.host = host.to_string();
+        self
+    }
+
     /// Set the port number.
     pub fn with_port(mut self, port: u16) -> Self {
         self.port = port;
         self
     }
 
+    /// Add an additional named listener port, e.g. for metrics.
+    pub fn with_listener(mut self, name: &str, port: u16) -> Self {
+        self.listeners.insert(name.to_string(), port);
+        self
+    }
+
     /// Enable or disable debug mode.
     pub fn with_debug(mut self, debug: bool) -> Self {
         self.debug = debug;
         self
     }
 
+    /// Reset every setting to its default, keeping only the name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let config = twoslash_demo::Config::new("my-app")
+    ///     .with_host("127.0.0.1")
+    ///     .with_port(8080)
+    ///     .with_debug(true)
+    ///     .with_listener("metrics", 9100)
+    ///     .reset_to_defaults();
+    ///
+    /// assert_eq!(config.name(), "my-app");
+    /// assert_eq!(config.address(), "0.0.0.0:3000");
+    /// assert!(!config.debug());
+    /// assert_eq!(config.listener_address("metrics"), None);
+    /// ```
+    pub fn reset_to_defaults(self) -> Self {
+        Self::new(&self.name)
+    }
+
     /// Get the application name.
     pub fn name(&self) -> &str {
         &self.name
     }
 
+    /// Whether debug mode is enabled.
+    pub fn debug(&self) -> bool {
+        self.debug
+    }
+
     /// Get the full address string.
     ///
     /// # Examples
@@ -121,8 +221,48 @@ impl Config {
     /// assert_eq!(addr, "0.0.0.0:9090");
     /// ```
     pub fn address(&self) -> String {
-        format!("0.0.0.0:{}", self.port)
+        format!("{}:{}", self.host, self.port)
     }
+
+    /// Get the host and port separately.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let config = twoslash_demo::Config::new("app").with_port(9090);
+    /// assert_eq!(config.address_parts(), ("0.0.0.0", 9090));
+    ///
+    /// let local = config.with_host("127.0.0.1");
+    /// let (host, port) = local.address_parts();
+    /// assert_eq!((host, port), ("127.0.0.1", 9090));
+    /// ```
+    pub fn address_parts(&self) -> (&str, u16) {
+        (&self.host, self.port)
+    }
+
+    /// Get the address string of a named listener.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let config = twoslash_demo::Config::new("app")
+    ///     .with_listener("http", 8080)
+    ///     .with_listener("metrics", 9100);
+    ///
+    /// let http = config.listener_address("http");
+    /// let metrics = config.listener_address("metrics");
+    /// assert_eq!(http.as_deref(), Some("0.0.0.0:8080"));
+    /// assert_eq!(metrics.as_deref(), Some("0.0.0.0:9100"));
+    /// assert_eq!(config.listener_address("admin"), None);
+    /// ```
+    pub fn listener_address(&self, name: &str) -> Option<String> {
+        let port = self.listeners.get(name)?;
+        Some(format!("{}:{}", self.host, port))
+    }
+}
+
+fn env_var<T: FromStr>(key: &str) -> Option<T> {
+    std::env::var(key).ok()?.parse().ok()
 }
 
 /// Process a list of items, filtering and transforming them.
@@ -144,6 +284,78 @@ pub fn process_items(items: &[&str], min_len: usize) -> Vec<String> {
         .collect()
 }
 
+/// Like [`process_items`], but groups the results by their first character.
+///
+/// # Examples
+///
+/// ```rust
+/// let words = vec!["rust", "hello", "ruby", "go", "haskell"];
+/// let grouped = twoslash_demo::process_items_grouped(&words, 3);
+///
+/// let keys: Vec<&char> = grouped.keys().collect();
+/// assert_eq!(keys, [&'H', &'R']);
+/// assert_eq!(grouped[&'R'], ["RUST", "RUBY"]);
+/// assert!(twoslash_demo::process_items_grouped(&words, 10).is_empty());
+/// assert!(twoslash_demo::process_items_grouped(&[""], 0).is_empty());
+/// ```
+pub fn process_items_grouped(items: &[&str], min_len: usize) -> BTreeMap<char, Vec<String>> {
+    let mut grouped: BTreeMap<char, Vec<String>> = BTreeMap::new();
+    for item in process_items(items, min_len) {
+        if let Some(first) = item.chars().next() {
+            grouped.entry(first).or_default().push(item);
+        }
+    }
+    grouped
+}
+
+/// Diagnostic entry produced by [`process_items_report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemReport<'a> {
+    /// The original item.
+    pub item: &'a str,
+    /// Length in bytes, as used by [`process_items`] for filtering.
+    pub byte_len: usize,
+    /// Length in `char`s.
+    pub char_len: usize,
+    /// Whether [`process_items`] keeps this item.
+    pub kept: bool,
+}
+
+impl ItemReport<'_> {
+    /// Whether the item lands on different sides of `min_len` depending on
+    /// whether bytes or chars are counted.
+    pub fn straddles(&self, min_len: usize) -> bool {
+        (self.byte_len >= min_len) != (self.char_len >= min_len)
+    }
+}
+
+/// Like [`process_items`], but reports byte and char lengths for every item
+/// instead of filtering, to surface multibyte pitfalls.
+///
+/// # Examples
+///
+/// ```rust
+/// let words = vec!["cafe", "café", "rust"];
+/// let report = twoslash_demo::process_items_report(&words, 5);
+///
+/// let cafe = &report[1];
+/// assert_eq!((cafe.byte_len, cafe.char_len), (5, 4));
+/// assert!(cafe.kept);
+/// assert!(cafe.straddles(5));
+/// assert!(!report[0].kept && !report[0].straddles(5));
+/// ```
+pub fn process_items_report<'a>(items: &[&'a str], min_len: usize) -> Vec<ItemReport<'a>> {
+    items
+        .iter()
+        .map(|&item| ItemReport {
+            item,
+            byte_len: item.len(),
+            char_len: item.chars().count(),
+            kept: item.len() >= min_len,
+        })
+        .collect()
+}
+
 /// A generic key-value store.
 ///
 /// # Examples
@@ -159,7 +371,7 @@ pub struct Store<K, V> {
     data: HashMap<K, V>,
 }
 
-impl<K: std::hash::Hash + Eq, V> Store<K, V> {
+impl<K: Hash + Eq, V> Store<K, V> {
     /// Create an empty store.
     pub fn new() -> Self {
         Self {
@@ -173,14 +385,62 @@ impl<K: std::hash::Hash + Eq, V> Store<K, V> {
     }
 
     /// Get a reference to a value by key.
-    pub fn get(&self, key: &K) -> Option<&V> {
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
         self.data.get(key)
     }
 
     /// Check if a key exists.
-    pub fn contains(&self, key: &K) -> bool {
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
         self.data.contains_key(key)
     }
+
+    /// Revert the store to a previously taken snapshot.
+    pub fn restore(&mut self, snap: StoreSnapshot<K, V>) {
+        self.data = snap.data;
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Store<K, V> {
+    /// Capture the current contents so they can be restored later.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut store = twoslash_demo::Store::<&str, i32>::new();
+    /// store.set("a", 1);
+    ///
+    /// let snap = store.snapshot();
+    /// store.set("a", 2);
+    /// store.set("b", 3);
+    ///
+    /// store.restore(snap);
+    /// assert_eq!(store.get("a"), Some(&1));
+    /// assert!(!store.contains("b"));
+    /// ```
+    pub fn snapshot(&self) -> StoreSnapshot<K, V> {
+        StoreSnapshot {
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> Default for Store<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A point-in-time copy of a [`Store`], created by [`Store::snapshot`].
+pub struct StoreSnapshot<K, V> {
+    data: HashMap<K, V>,
 }
 
 /// Demonstrates iterator chains and type inference.
@@ -345,3 +605,764 @@ pub fn hex_demo() {}
 /// let all_keys: Vec<&&str> = encoded.keys().collect();
 /// ```
 pub fn hex_collections_demo() {}
+
+/// Type-directed terminal methods: `sum`, `product`, `collect`, and `parse`.
+///
+/// # Examples
+///
+/// ```rust
+/// let numbers = vec![1, 2, 3, 4];
+///
+/// let total: i32 = numbers.iter().sum();
+/// let product = numbers.iter().product::<i32>();
+///
+/// let doubled = numbers.iter().map(|n| n * 2).collect::<Vec<_>>();
+/// let strings: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+///
+/// let port: u16 = "8080".parse().unwrap();
+/// let ratio = "0.75".parse::<f32>().unwrap();
+/// ```
+pub fn type_directed_demo() {}
+
+/// Tokenizer edge cases: raw strings, byte literals, nested comments, and
+/// lifetimes next to char literals.
+///
+/// # Examples
+///
+/// ```rust
+/// let raw = r#"a "quoted" </span> "#;
+/// let raw_hashes = r##"contains "# inside"##;
+/// let bytes = b"\xff\x00<\"";
+/// let byte = b'\'';
+///
+/// /* outer /* nested */ still a comment */
+/// let tick = '\'';
+/// fn longest<'a>(a: &'a str, b: &'a str) -> &'a str {
+///     if a.len() >= b.len() { a } else { b }
+/// }
+///
+/// let café = "naïve <&> ünïcödé";
+/// let pick = longest(raw, café);
+/// ```
+pub fn tokenizer_edge_cases_demo() {}
+
+/// Re-exported types and their canonical paths.
+///
+/// # Examples
+///
+/// ```rust
+/// mod geometry {
+///     pub mod shapes {
+///         pub struct Circle {
+///             pub radius: f64,
+///         }
+///     }
+///
+///     pub use shapes::Circle;
+///     pub use shapes::Circle as Round;
+/// }
+///
+/// let via_reexport = geometry::Circle { radius: 1.0 };
+/// let via_alias = geometry::Round { radius: 2.0 };
+/// let via_canonical = geometry::shapes::Circle { radius: 3.0 };
+/// ```
+pub fn reexports_demo() {}
+
+/// Loops that break with a value.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut counter = 0;
+/// let doubled = loop {
+///     counter += 1;
+///     if counter == 10 {
+///         break counter * 2;
+///     }
+/// };
+///
+/// let grid = [[1, 2], [3, 4]];
+/// let found = 'outer: loop {
+///     for row in &grid {
+///         for &cell in row {
+///             if cell > 2 {
+///                 break 'outer Some(cell);
+///             }
+///         }
+///     }
+///     break None;
+/// };
+/// ```
+pub fn loop_values_demo() {}
+
+/// Standard library macros and their result types.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fmt::Write;
+///
+/// let maybe = Some(42);
+/// let is_some = matches!(maybe, Some(_));
+/// let is_big = matches!(maybe, Some(n) if n > 100);
+///
+/// let four = dbg!(2 + 2);
+/// let passthrough = dbg!(maybe.unwrap_or_default() + four);
+/// assert!(is_some && !is_big, "unexpected match results");
+///
+/// let mut out = String::new();
+/// let written = write!(out, "{passthrough}");
+/// ```
+pub fn std_macros_demo() {}
+
+/// Generic calls whose type parameters are fixed by later use.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut ids = Vec::new();
+/// ids.push(7_u64);
+///
+/// let mut lookup = std::collections::HashMap::new();
+/// lookup.insert("answer", 42.0);
+///
+/// let parsed = Default::default();
+/// let _: (String, bool) = parsed;
+/// ```
+pub fn generic_inference_demo() {}
+
+/// Deref and unsize coercions at call sites.
+///
+/// # Examples
+///
+/// ```rust
+/// fn shout(text: &str) -> String {
+///     text.to_uppercase()
+/// }
+///
+/// fn total(values: &[i32]) -> i32 {
+///     values.iter().sum()
+/// }
+///
+/// let owned = String::from("hello");
+/// let loud = shout(&owned);
+///
+/// let boxed: Box<String> = Box::new("boxed".into());
+/// let also_loud = shout(&boxed);
+///
+/// let array = [1, 2, 3];
+/// let sum = total(&array);
+/// ```
+pub fn coercions_demo() {}
+
+/// `let ... else` bindings.
+///
+/// # Examples
+///
+/// ```rust
+/// fn describe(input: Option<&str>, pair: Result<(u8, char), ()>) -> String {
+///     let Some(text) = input else {
+///         return "nothing".into();
+///     };
+///     let Ok((count, symbol)) = pair else {
+///         return text.to_string();
+///     };
+///     format!("{text}: {}", symbol.to_string().repeat(count as usize))
+/// }
+///
+/// let full = describe(Some("stars"), Ok((3, '*')));
+/// let partial = describe(Some("stars"), Err(()));
+/// ```
+pub fn let_else_demo() {}
+
+/// Owned conversions: `clone`, `to_owned`, `to_string`, and `into`.
+///
+/// # Examples
+///
+/// ```rust
+/// let name: &str = "my-app";
+/// let owned = name.to_owned();
+/// let text = name.to_string();
+///
+/// let borrowed: &Vec<i32> = &vec![1, 2, 3];
+/// let cloned = borrowed.clone();
+///
+/// let converted: String = name.into();
+/// let widened: i64 = 7_i32.into();
+///
+/// let via_from = String::from("Hello!");
+/// let via_trait: Vec<u8> = From::from("bytes");
+/// ```
+pub fn owned_conversions_demo() {}
+
+/// Code blocks with different fence info strings.
+///
+/// # Examples
+///
+/// A `rust` fence with an attribute:
+///
+/// ```rust,edition2021
+/// let pair = (1, "one");
+/// let closure = move || pair.1.len();
+/// let len = closure();
+/// ```
+///
+/// The short `rs` form, which stock rustdoc does not treat as Rust:
+///
+/// ```rs
+/// let words = vec!["short", "fence"];
+/// let joined = words.join(" ");
+/// ```
+///
+/// A fence in another language, which is never analyzed:
+///
+/// ```python
+/// words = ["short", "fence"]
+/// joined = " ".join(words)
+/// ```
+pub fn fence_attributes_demo() {}
+
+/// Calls that resolve to a trait's provided (default) method.
+///
+/// # Examples
+///
+/// ```rust
+/// trait Describe {
+///     fn name(&self) -> String;
+///
+///     fn describe(&self) -> String {
+///         format!("This is {}", self.name())
+///     }
+/// }
+///
+/// struct Robot;
+///
+/// impl Describe for Robot {
+///     fn name(&self) -> String { "a robot".into() }
+/// }
+///
+/// let text = Robot.describe();
+/// ```
+pub fn default_methods_demo() {}
+
+/// `?` converting errors through a custom `From` impl.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::num::ParseIntError;
+///
+/// #[derive(Debug)]
+/// enum AppError {
+///     BadNumber(ParseIntError),
+/// }
+///
+/// impl From<ParseIntError> for AppError {
+///     fn from(err: ParseIntError) -> Self {
+///         AppError::BadNumber(err)
+///     }
+/// }
+///
+/// fn parse_port(input: &str) -> Result<u16, AppError> {
+///     let port = input.parse::<u16>()?;
+///     Ok(port)
+/// }
+///
+/// let good = parse_port("8080");
+/// let bad = parse_port("eighty");
+/// ```
+pub fn custom_error_demo() {}
+
+/// Declared binding types that are looser than the inferred type.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fmt::Display;
+///
+/// let concrete = 42_u8;
+/// let shown: &dyn Display = &concrete;
+///
+/// let inferred = vec![1.5, 2.5];
+/// let declared: &[f64] = &inferred;
+/// let rendered = shown.to_string();
+/// ```
+pub fn declared_vs_inferred_demo() {}
+
+/// Slice patterns with rest and sub-slice bindings.
+///
+/// # Examples
+///
+/// ```rust
+/// let arr = [10, 20, 30, 40, 50];
+/// let [first, .., last] = arr;
+///
+/// let [head, rest @ ..] = arr;
+///
+/// let words: &[&str] = &["a", "b", "c"];
+/// if let [only_first, middle @ .., _] = words {
+///     let middle_len = middle.len();
+///     let shout = only_first.to_uppercase();
+/// }
+/// ```
+pub fn slice_patterns_demo() {}
+
+/// `Result` combinator chains.
+///
+/// # Examples
+///
+/// ```rust
+/// let input = "21";
+///
+/// let parsed = input.parse::<i64>();
+/// let described = parsed.clone().map_err(|err| err.to_string());
+/// let doubled = parsed.clone().and_then(|n| (n * 2).to_string().parse::<i64>());
+///
+/// let value = doubled.unwrap_or(0);
+/// let halved = parsed.map(|n| n / 2).unwrap_or_default();
+///
+/// let maybe_name: Option<&str> = Some("ferris");
+/// let name_len = maybe_name.map(|name| name.len());
+/// let initial = maybe_name.and_then(|name| name.chars().next());
+/// let fallback = "x".parse::<u8>().unwrap_or_else(|err| err.to_string().len() as u8);
+/// ```
+pub fn result_combinators_demo() {}
+
+/// `Self` and associated types inside trait impl bodies.
+///
+/// # Examples
+///
+/// ```rust
+/// trait Shape {
+///     type Unit;
+///
+///     fn unit() -> Self::Unit;
+///     fn scaled(&self, factor: f64) -> Self;
+/// }
+///
+/// struct Square {
+///     side: f64,
+/// }
+///
+/// impl Shape for Square {
+///     type Unit = &'static str;
+///
+///     fn unit() -> Self::Unit { "cm" }
+///
+///     fn scaled(&self, factor: f64) -> Self {
+///         let grown = Self { side: self.side * factor };
+///         grown
+///     }
+/// }
+///
+/// let big = Square { side: 2.0 }.scaled(3.0);
+/// let unit = Square::unit();
+/// ```
+pub fn self_in_impls_demo() {}
+
+/// Constants with compile-time evaluated values.
+///
+/// # Examples
+///
+/// ```rust
+/// const N: usize = 2 + 3;
+/// const MASK: u8 = 1 << 4 | 0b11;
+/// const GREETING: &str = "hello";
+///
+/// let buffer = [0u8; N];
+/// let flags = MASK & 0x0f;
+/// let full = buffer.len() == N;
+/// let greeting_len = GREETING.len();
+///
+/// let pi = std::f64::consts::PI;
+/// let tau = std::f64::consts::TAU;
+/// let biggest = u32::MAX;
+/// ```
+pub fn consts_demo() {}
+
+/// Numeric literals that fall back to the default `i32` and `f64` types.
+///
+/// # Examples
+///
+/// ```rust
+/// let x = 5;
+/// let y = 5.0;
+///
+/// let explicit = 5_u64;
+/// let inferred: u8 = 5;
+/// let mixed = y * 2.0;
+/// ```
+pub fn numeric_defaults_demo() {}
+
+/// Expressions inside `unsafe` blocks.
+///
+/// # Examples
+///
+/// ```rust
+/// let value = 42_i32;
+/// let ptr: *const i32 = &value;
+///
+/// let read = unsafe { *ptr };
+/// let offset = unsafe { ptr.add(0).read() + 1 };
+/// ```
+pub fn unsafe_blocks_demo() {}
+
+/// Object-safe and non-object-safe trait methods.
+///
+/// # Examples
+///
+/// ```rust
+/// trait Animal {
+///     fn name(&self) -> String;
+///
+///     fn duplicate(&self) -> Self
+///     where
+///         Self: Sized;
+///
+///     fn compare<T: Animal>(&self, other: &T) -> bool
+///     where
+///         Self: Sized,
+///     {
+///         self.name() == other.name()
+///     }
+/// }
+///
+/// #[derive(Clone)]
+/// struct Cat;
+///
+/// impl Animal for Cat {
+///     fn name(&self) -> String { "cat".into() }
+///     fn duplicate(&self) -> Self { self.clone() }
+/// }
+///
+/// let pets: Vec<Box<dyn Animal>> = vec![Box::new(Cat)];
+/// let names: Vec<String> = pets.iter().map(|p| p.name()).collect();
+/// let same = Cat.compare(&Cat.duplicate());
+/// ```
+pub fn object_safety_demo() {}
+
+/// User-defined `macro_rules!` invocations that expand to expressions.
+///
+/// # Examples
+///
+/// ```rust
+/// macro_rules! square {
+///     ($x:expr) => {
+///         $x * $x
+///     };
+/// }
+///
+/// macro_rules! pair {
+///     ($a:expr, $b:expr) => {
+///         ($a, $b.to_string())
+///     };
+/// }
+///
+/// let area = square!(7);
+/// let labelled = pair!(area, "cm2");
+/// ```
+pub fn macro_rules_demo() {}
+
+/// Iterator chains whose element type changes between stages.
+///
+/// # Examples
+///
+/// ```rust
+/// let numbers = vec![1, 2, 3];
+///
+/// let labels = numbers.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+///
+/// let nested = vec![vec!['a', 'b'], vec!['c']];
+/// let flat: String = nested.into_iter().flatten().collect();
+///
+/// let lengths = labels.iter().map(String::len).filter(|&len| len > 0).count();
+/// ```
+pub fn element_type_chains_demo() {}
+
+/// Functions returning `impl Iterator`.
+///
+/// # Examples
+///
+/// ```rust
+/// fn evens(limit: i32) -> impl Iterator<Item = i32> {
+///     (0..limit).filter(|n| n % 2 == 0)
+/// }
+///
+/// let iter = evens(10);
+/// let squares: Vec<i32> = evens(5).map(|n| n * n).collect();
+/// let total: i32 = iter.sum();
+/// ```
+pub fn impl_iterator_demo() {}
+
+/// Shadowed loop variables across nested loops.
+///
+/// # Examples
+///
+/// ```rust
+/// let rows = vec!["ab", "cde"];
+/// let mut seen = Vec::new();
+///
+/// for i in 0..rows.len() {
+///     for i in rows[i].chars() {
+///         seen.push(i);
+///     }
+/// }
+/// ```
+pub fn shadowing_demo() {}
+
+/// Zero-sized types: unit structs and `PhantomData` markers.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::marker::PhantomData;
+///
+/// struct English;
+/// struct Spanish;
+/// struct Greeting<Lang> {
+///     text: String,
+///     lang: PhantomData<Lang>,
+/// }
+///
+/// let english = English;
+/// let spanish = Spanish;
+/// let phantom: PhantomData<Spanish> = PhantomData;
+/// let greeting = Greeting { text: "¡Hola!".into(), lang: phantom };
+///
+/// let zero = std::mem::size_of_val(&english);
+/// ```
+pub fn zero_sized_demo() {}
+
+/// Top-level `?` in an example, converting into `Box<dyn Error>`.
+///
+/// # Examples
+///
+/// ```rust
+/// let port: u16 = "8080".parse()?;
+/// let decoded = const_hex::decode("48656c6c6f")?;
+/// let text = String::from_utf8(decoded.clone())?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn top_level_try_demo() {}
+
+/// `impl Trait` in argument position.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fmt::Display;
+///
+/// fn total(values: impl Iterator<Item = i32>) -> i32 {
+///     let mut sum = 0;
+///     for value in values {
+///         sum += value;
+///     }
+///     sum
+/// }
+///
+/// fn label(item: impl Display) -> String {
+///     format!("<{item}>")
+/// }
+///
+/// let sum = total(vec![1, 2, 3].into_iter());
+/// let tag = label(sum);
+/// ```
+pub fn impl_trait_args_demo() {}
+
+/// Tuple-struct and newtype construction.
+///
+/// # Examples
+///
+/// ```rust
+/// struct Meters(f64);
+/// struct Rgb(u8, u8, u8);
+///
+/// let m = Meters(5.0);
+/// let teal = Rgb(0, 128, 128);
+///
+/// let Meters(raw) = m;
+/// let green = teal.1;
+/// ```
+pub fn newtypes_demo() {}
+
+/// `?` chained across fallible builder calls.
+///
+/// # Examples
+///
+/// ```rust
+/// #[derive(Debug)]
+/// struct Request {
+///     url: String,
+///     retries: u8,
+/// }
+///
+/// impl Request {
+///     fn new(url: &str) -> Result<Self, String> {
+///         if url.starts_with("https://") {
+///             Ok(Request { url: url.into(), retries: 0 })
+///         } else {
+///             Err(format!("insecure url: {url}"))
+///         }
+///     }
+///
+///     fn try_retries(mut self, retries: u8) -> Result<Self, String> {
+///         if retries > 5 {
+///             return Err("too many retries".into());
+///         }
+///         self.retries = retries;
+///         Ok(self)
+///     }
+/// }
+///
+/// fn build() -> Result<Request, String> {
+///     let request = Request::new("https://example.com")?.try_retries(3)?;
+///     Ok(request)
+/// }
+///
+/// let built = build();
+/// ```
+pub fn fallible_builder_demo() {}
+
+/// Generic parameters with defaults, such as the hasher of `HashMap`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use std::hash::BuildHasherDefault;
+/// use std::collections::hash_map::DefaultHasher;
+///
+/// let default_hasher: HashMap<&str, i32> = HashMap::new();
+///
+/// type Fixed = BuildHasherDefault<DefaultHasher>;
+/// let custom_hasher: HashMap<&str, i32, Fixed> = HashMap::default();
+/// ```
+pub fn default_generics_demo() {}
+
+/// Iterating maps by reference.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::{BTreeMap, HashMap};
+///
+/// let mut stock: HashMap<String, u32> = HashMap::new();
+/// stock.insert("apples".into(), 3);
+///
+/// for (k, v) in &stock {
+///     let line = format!("{k}: {v}");
+/// }
+///
+/// let sorted: BTreeMap<&str, u32> = stock.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+/// ```
+pub fn map_iteration_demo() {}
+
+/// Range-based slicing of vectors and strings.
+///
+/// # Examples
+///
+/// ```rust
+/// let v = vec![10, 20, 30, 40];
+/// let middle = &v[1..3];
+/// let tail = &v[2..];
+///
+/// let text = "hello world";
+/// let word = &text[..5];
+/// ```
+pub fn slicing_demo() {}
+
+/// `if let` and `while let` scrutinees and bindings.
+///
+/// # Examples
+///
+/// ```rust
+/// let opt = Some(3);
+/// if let Some(x) = opt {
+///     let doubled = x * 2;
+/// }
+///
+/// let pair = Some(("id", 7_u32));
+/// if let Some((a, b)) = pair {
+///     let label = format!("{a}={b}");
+/// }
+///
+/// let mut stack = vec!['a', 'b', 'c'];
+/// while let Some(top) = stack.pop() {
+///     let upper = top.to_ascii_uppercase();
+/// }
+/// ```
+pub fn if_let_demo() {}
+
+/// Calling an `async fn` and awaiting its future.
+///
+/// # Examples
+///
+/// ```rust
+/// async fn fetch_len(url: &str) -> usize {
+///     url.len()
+/// }
+///
+/// let pending = fetch_len("https://example.com");
+///
+/// let task = async {
+///     let len = fetch_len("https://rust-lang.org").await;
+///     len * 2
+/// };
+/// ```
+pub fn async_demo() {}
+
+/// Nested smart-pointer and wrapper types.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// let shared = Rc::new(RefCell::new(vec![1, 2, 3]));
+/// let handle = Rc::clone(&shared);
+/// handle.borrow_mut().push(4);
+///
+/// let borrowed: Cow<'_, str> = Cow::Borrowed("static");
+/// let owned: Cow<'_, str> = Cow::Owned(format!("{} items", shared.borrow().len()));
+/// ```
+pub fn wrapper_types_demo() {}
+
+/// Turbofish on `collect` with inferred `_` slots.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// let entries = vec![("alice", 95), ("bob", 87)];
+/// let scores = entries.iter().copied().collect::<HashMap<_, _>>();
+///
+/// let bob = scores["bob"];
+/// ```
+pub fn turbofish_demo() {}
+
+/// Long paths mixing module segments and a final type.
+///
+/// # Examples
+///
+/// ```rust
+/// let map = std::collections::HashMap::from([("a", 1), ("b", 2)]);
+/// let values: std::collections::hash_map::Values<'_, &str, i32> = map.values();
+/// let largest = values.max();
+/// ```
+pub fn long_paths_demo() {}
+
+/// Collecting fallible results into `Result<Vec<_>, _>`.
+///
+/// # Examples
+///
+/// ```rust
+/// let inputs = ["1", "2", "3"];
+/// let parsed = inputs.iter().map(|s| s.parse::<i64>()).collect::<Result<Vec<_>, _>>();
+///
+/// let mixed = ["4", "five"];
+/// let failed: Result<Vec<i64>, _> = mixed.iter().map(|s| s.parse::<i64>()).collect();
+/// ```
+pub fn collect_results_demo() {}